@@ -0,0 +1,73 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{Screen, Theme};
+
+/// Floors below this turn `iced::time::every` into a busy loop, so a zero or
+/// too-small config/CLI value is clamped up to it rather than honored as-is.
+pub const MIN_UPDATE_RATE_MS: u64 = 50;
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ColorOverrides {
+    pub background: Option<String>,
+    pub foreground: Option<String>,
+    pub accent: Option<String>,
+    pub header_bg: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct Config {
+    pub theme: Option<String>,
+    pub update_rate_ms: Option<u64>,
+    pub default_screen: Option<String>,
+    #[serde(default)]
+    pub colors: ColorOverrides,
+}
+
+impl Config {
+    /// Loads the config from the standard config dir, falling back to defaults
+    /// (hardcoded `Theme::Dark`, 1000ms tick, `Screen::Main`) when no file is present
+    /// or it fails to parse.
+    pub fn load() -> Config {
+        match Self::config_path().and_then(|path| fs::read_to_string(path).ok()) {
+            Some(contents) => toml::from_str(&contents).unwrap_or_default(),
+            None => Config::default(),
+        }
+    }
+
+    fn config_path() -> Option<PathBuf> {
+        let dir = directories::ProjectDirs::from("", "", "rustmanager")?;
+        Some(dir.config_dir().join("config.toml"))
+    }
+
+    pub fn theme(&self) -> Theme {
+        match self.theme.as_deref() {
+            Some("light") => Theme::Light,
+            _ => Theme::Dark,
+        }
+    }
+
+    pub fn update_rate_ms(&self) -> u64 {
+        self.update_rate_ms.unwrap_or(1000).max(MIN_UPDATE_RATE_MS)
+    }
+
+    pub fn default_screen(&self) -> Screen {
+        match self.default_screen.as_deref() {
+            Some("graph") => Screen::Graph,
+            Some("disks") => Screen::Disks,
+            _ => Screen::Main,
+        }
+    }
+}
+
+pub fn parse_hex_color(hex: &str) -> Option<iced::Color> {
+    let hex = hex.trim_start_matches('#');
+    if hex.len() != 6 {
+        return None;
+    }
+    let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+    let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+    let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+    Some(iced::Color::from_rgb8(r, g, b))
+}
@@ -5,13 +5,113 @@ use iced::{
     widget::container,
 };
 use iced::widget::canvas::{Canvas, Stroke, Frame, Path};
-use sysinfo::{CpuExt, System, SystemExt, ProcessExt, Pid};
+use sysinfo::{CpuExt, System, SystemExt, ProcessExt, Pid, NetworkExt, NetworksExt, DiskExt, ComponentExt};
+use std::collections::HashMap;
 use std::time::Duration;
 use std::fs;
 
+mod config;
+use config::Config;
+
+fn format_bytes_per_sec(bytes: f64) -> String {
+    if bytes >= 1_048_576.0 {
+        format!("{:.2} MB/s", bytes / 1_048_576.0)
+    } else if bytes >= 1024.0 {
+        format!("{:.2} KB/s", bytes / 1024.0)
+    } else {
+        format!("{:.0} B/s", bytes)
+    }
+}
+
+const CORE_COLOR_SEEDS: [Color; 6] = [
+    Color::from_rgb(0.9, 0.3, 0.3),
+    Color::from_rgb(0.3, 0.6, 0.9),
+    Color::from_rgb(0.9, 0.7, 0.2),
+    Color::from_rgb(0.4, 0.8, 0.4),
+    Color::from_rgb(0.7, 0.4, 0.9),
+    Color::from_rgb(0.9, 0.5, 0.7),
+];
+const GOLDEN_RATIO_CONJUGATE: f32 = 0.618034;
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Color {
+    let i = (h * 6.0).floor();
+    let f = h * 6.0 - i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - f * s);
+    let t = v * (1.0 - (1.0 - f) * s);
+
+    let (r, g, b) = match (i as i64).rem_euclid(6) {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+    Color::from_rgb(r, g, b)
+}
+
+fn core_color(index: usize) -> Color {
+    if index < CORE_COLOR_SEEDS.len() {
+        return CORE_COLOR_SEEDS[index];
+    }
+    let extra = (index - CORE_COLOR_SEEDS.len()) as f32;
+    let hue = (0.1 + GOLDEN_RATIO_CONJUGATE * (extra + 1.0)).rem_euclid(1.0);
+    hsv_to_rgb(hue, 0.65, 0.95)
+}
+
+fn format_bytes_total(bytes: u64) -> String {
+    let bytes = bytes as f64;
+    if bytes >= 1_073_741_824.0 {
+        format!("{:.2} GB", bytes / 1_073_741_824.0)
+    } else if bytes >= 1_048_576.0 {
+        format!("{:.2} MB", bytes / 1_048_576.0)
+    } else if bytes >= 1024.0 {
+        format!("{:.2} KB", bytes / 1024.0)
+    } else {
+        format!("{:.0} B", bytes)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Theme { Light, Dark }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TemperatureUnit { Celsius, Fahrenheit, Kelvin }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProcessSorting { Cpu, Mem, Pid, Name }
+
+impl ProcessSorting {
+    fn label(&self) -> &'static str {
+        match self {
+            ProcessSorting::Cpu => "CPU (%)",
+            ProcessSorting::Mem => "Memory (MB)",
+            ProcessSorting::Pid => "PID",
+            ProcessSorting::Name => "Process Name",
+        }
+    }
+}
+
+impl TemperatureUnit {
+    fn convert(&self, celsius: f32) -> f32 {
+        match self {
+            TemperatureUnit::Celsius => celsius,
+            TemperatureUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+            TemperatureUnit::Kelvin => celsius + 273.15,
+        }
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Celsius => "C",
+            TemperatureUnit::Fahrenheit => "F",
+            TemperatureUnit::Kelvin => "K",
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
 struct ThemePalette {
     background: Color,
     foreground: Color,
@@ -41,11 +141,37 @@ impl Theme {
     }
 }
 
-struct CustomContainerStyle(Theme);
+#[derive(Clone, Copy, Default)]
+struct PaletteOverrides {
+    background: Option<Color>,
+    foreground: Option<Color>,
+    accent: Option<Color>,
+    header_bg: Option<Color>,
+}
+
+impl PaletteOverrides {
+    fn from_config(colors: &config::ColorOverrides) -> Self {
+        Self {
+            background: colors.background.as_deref().and_then(config::parse_hex_color),
+            foreground: colors.foreground.as_deref().and_then(config::parse_hex_color),
+            accent: colors.accent.as_deref().and_then(config::parse_hex_color),
+            header_bg: colors.header_bg.as_deref().and_then(config::parse_hex_color),
+        }
+    }
+
+    fn apply(&self, palette: &mut ThemePalette) {
+        if let Some(color) = self.background { palette.background = color; }
+        if let Some(color) = self.foreground { palette.foreground = color; }
+        if let Some(color) = self.accent { palette.accent = color; }
+        if let Some(color) = self.header_bg { palette.header_bg = color; }
+    }
+}
+
+struct CustomContainerStyle(ThemePalette);
 impl container::StyleSheet for CustomContainerStyle {
     type Style = iced::Theme;
     fn appearance(&self, _style: &Self::Style) -> container::Appearance {
-        let palette = self.0.palette();
+        let palette = self.0;
         container::Appearance {
             background: Some(palette.background.into()),
             text_color: Some(palette.foreground),
@@ -61,8 +187,87 @@ impl container::StyleSheet for RowContainerStyle {
     }
 }
 
+#[derive(Debug, Clone, Default)]
+struct CliArgs {
+    theme: Option<Theme>,
+    update_rate_ms: Option<u64>,
+    default_screen: Option<Screen>,
+}
+
+fn parse_cli_args() -> CliArgs {
+    let mut args = CliArgs::default();
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--theme" => {
+                if let Some(value) = iter.next() {
+                    args.theme = match value.as_str() {
+                        "light" => Some(Theme::Light),
+                        "dark" => Some(Theme::Dark),
+                        _ => None,
+                    };
+                }
+            }
+            "--update-rate-ms" => {
+                if let Some(value) = iter.next() {
+                    args.update_rate_ms = value.parse().ok();
+                }
+            }
+            "--screen" => {
+                if let Some(value) = iter.next() {
+                    args.default_screen = match value.as_str() {
+                        "main" => Some(Screen::Main),
+                        "graph" => Some(Screen::Graph),
+                        "disks" => Some(Screen::Disks),
+                        _ => None,
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+    args
+}
+
+#[derive(Clone)]
+struct StartupFlags {
+    theme: Theme,
+    update_rate_ms: u64,
+    default_screen: Screen,
+    palette: ThemePalette,
+    palette_overrides: PaletteOverrides,
+}
+
 pub fn main() -> iced::Result {
-    SystemMonitor::run(Settings::default())
+    let config = Config::load();
+    let cli = parse_cli_args();
+
+    let theme = cli.theme.unwrap_or_else(|| config.theme());
+    let update_rate_ms = cli.update_rate_ms.unwrap_or_else(|| config.update_rate_ms())
+        .max(config::MIN_UPDATE_RATE_MS);
+    let default_screen = cli.default_screen.unwrap_or_else(|| config.default_screen());
+
+    let palette_overrides = PaletteOverrides::from_config(&config.colors);
+    let mut palette = theme.palette();
+    palette_overrides.apply(&mut palette);
+
+    SystemMonitor::run(Settings::with_flags(StartupFlags {
+        theme,
+        update_rate_ms,
+        default_screen,
+        palette,
+        palette_overrides,
+    }))
+}
+
+struct DiskInfo {
+    name: String,
+    mount_point: String,
+    used_bytes: u64,
+    free_bytes: u64,
+    total_bytes: u64,
+    read_rate: f64,
+    write_rate: f64,
 }
 
 struct SystemMonitor {
@@ -73,54 +278,83 @@ struct SystemMonitor {
     cpu_history: Vec<f32>,
     memory_history: Vec<f32>,
     current_theme: Theme,
+    rx_rate: f64,
+    tx_rate: f64,
+    total_rx: u64,
+    total_tx: u64,
+    rx_history: Vec<f32>,
+    tx_history: Vec<f32>,
+    prev_net_totals: Option<(u64, u64)>,
+    disks: Vec<DiskInfo>,
+    prev_disk_io: HashMap<String, (u64, u64)>,
+    sensors: Vec<(String, f32)>,
+    temperature_unit: TemperatureUnit,
+    cpu_per_core_history: Vec<Vec<f32>>,
+    show_per_core_cpu: bool,
+    active_palette: ThemePalette,
+    palette_overrides: PaletteOverrides,
+    update_rate_ms: u64,
+    process_sorting: ProcessSorting,
+    sort_reverse: bool,
+    pending_kill: Option<(Pid, String)>,
+    kill_error: Option<String>,
+    is_frozen: bool,
 }
 
 #[derive(Debug, Clone)]
-enum Screen { Main, Graph }
+enum Screen { Main, Graph, Disks }
 
 #[derive(Debug, Clone)]
 enum Message {
     Tick,
     GoToGraph,
+    GoToDisks,
     BackToMain,
     ThemeChanged(Theme),
-    EndTask(Pid),
+    UnitChanged(TemperatureUnit),
+    TogglePerCoreCpu,
+    SortBy(ProcessSorting),
+    RequestKill(Pid, String),
+    ConfirmKill,
+    CancelKill,
     ExportCSV,
+    ToggleFreeze,
 }
 
 struct CpuGraph {
     history: Vec<f32>,
     current: f32,
-    theme: Theme,
+    palette: ThemePalette,
+    per_core_history: Option<Vec<Vec<f32>>>,
 }
 
 impl<Message> canvas::Program<Message> for CpuGraph {
     type State = ();
     fn draw(&self, _: &Self::State, renderer: &iced::Renderer, _: &iced::Theme, bounds: iced::Rectangle, _: iced::mouse::Cursor)
         -> Vec<canvas::Geometry> {
-        let palette = self.theme.palette();
+        let palette = self.palette;
         let mut frame = Frame::new(renderer, bounds.size());
         let w = bounds.width;
         let h = bounds.height;
         let top_offset = 30.0;
-        let bottom_margin = 1.0; 
+        let bottom_margin = 1.0;
         let chart_height = h - top_offset - bottom_margin;
         let len = (self.history.len().max(1)) as f32;
         let step = w / len;
-        
+
         frame.stroke(
             &Path::rectangle([0.0, 0.0].into(), bounds.size()),
             Stroke::default().with_width(1.0).with_color(palette.line_separator),
         );
-        
+
         frame.stroke(
             &Path::line(
-                [0.0, h - bottom_margin].into(), 
+                [0.0, h - bottom_margin].into(),
                 [w, h - bottom_margin].into()
             ),
             Stroke::default().with_width(1.0).with_color(palette.line_separator),
         );
-        
+
         frame.fill_text(canvas::Text {
             content: format!("CPU: {:.2}%", self.current),
             position: [10.0, 20.0].into(),
@@ -128,12 +362,27 @@ impl<Message> canvas::Program<Message> for CpuGraph {
             color: palette.foreground,
             ..Default::default()
         });
-        
+
+        if let Some(per_core_history) = &self.per_core_history {
+            for (core_index, history) in per_core_history.iter().enumerate() {
+                let path = Path::new(|b| {
+                    for (i, v) in history.iter().enumerate() {
+                        let x = i as f32 * step;
+                        let y = top_offset + (chart_height - (v / 100.0 * chart_height));
+
+                        if i == 0 { b.move_to([x, y].into()); } else { b.line_to([x, y].into()); }
+                    }
+                });
+                frame.stroke(&path, Stroke::default().with_width(1.5).with_color(core_color(core_index)));
+            }
+            return vec![frame.into_geometry()];
+        }
+
         let path = Path::new(|b| {
             for (i, v) in self.history.iter().enumerate() {
                 let x = i as f32 * step;
                 let y = top_offset + (chart_height - (v / 100.0 * chart_height));
-                
+
                 if i == 0 { b.move_to([x, y].into()); } else { b.line_to([x, y].into()); }
             }
         });
@@ -145,14 +394,89 @@ impl<Message> canvas::Program<Message> for CpuGraph {
 struct MemGraph {
     history: Vec<f32>,
     current: f32,
-    theme: Theme,
+    palette: ThemePalette,
+}
+
+struct NetGraph {
+    rx_history: Vec<f32>,
+    tx_history: Vec<f32>,
+    rx_rate: f64,
+    tx_rate: f64,
+    palette: ThemePalette,
+}
+
+impl<Message> canvas::Program<Message> for NetGraph {
+    type State = ();
+    fn draw(&self, _: &Self::State, renderer: &iced::Renderer, _: &iced::Theme, bounds: iced::Rectangle, _: iced::mouse::Cursor)
+        -> Vec<canvas::Geometry> {
+        const RX_COLOR: Color = Color::from_rgb(0.1, 0.8, 0.3);
+        const TX_COLOR: Color = Color::from_rgb(0.9, 0.3, 0.3);
+
+        let palette = self.palette;
+        let mut frame = Frame::new(renderer, bounds.size());
+        let w = bounds.width;
+        let h = bounds.height;
+        let top_offset = 30.0;
+        let bottom_margin = 1.0;
+        let chart_height = h - top_offset - bottom_margin;
+        let max_val = self.rx_history.iter().chain(self.tx_history.iter())
+            .cloned()
+            .fold(1.0_f32, f32::max);
+        let len = (self.rx_history.len().max(1)) as f32;
+        let step = w / len;
+
+        frame.stroke(
+            &Path::rectangle([0.0, 0.0].into(), bounds.size()),
+            Stroke::default().with_width(1.0).with_color(palette.line_separator),
+        );
+
+        frame.stroke(
+            &Path::line(
+                [0.0, h - bottom_margin].into(),
+                [w, h - bottom_margin].into()
+            ),
+            Stroke::default().with_width(1.0).with_color(palette.line_separator),
+        );
+
+        frame.fill_text(canvas::Text {
+            content: format!(
+                "RX: {} | TX: {}",
+                format_bytes_per_sec(self.rx_rate),
+                format_bytes_per_sec(self.tx_rate)
+            ),
+            position: [10.0, 20.0].into(),
+            size: iced::Pixels(16.0),
+            color: palette.foreground,
+            ..Default::default()
+        });
+
+        let rx_path = Path::new(|b| {
+            for (i, v) in self.rx_history.iter().enumerate() {
+                let x = i as f32 * step;
+                let y = top_offset + (chart_height - (v / max_val * chart_height));
+                if i == 0 { b.move_to([x, y].into()); } else { b.line_to([x, y].into()); }
+            }
+        });
+        frame.stroke(&rx_path, Stroke::default().with_width(2.0).with_color(RX_COLOR));
+
+        let tx_path = Path::new(|b| {
+            for (i, v) in self.tx_history.iter().enumerate() {
+                let x = i as f32 * step;
+                let y = top_offset + (chart_height - (v / max_val * chart_height));
+                if i == 0 { b.move_to([x, y].into()); } else { b.line_to([x, y].into()); }
+            }
+        });
+        frame.stroke(&tx_path, Stroke::default().with_width(2.0).with_color(TX_COLOR));
+
+        vec![frame.into_geometry()]
+    }
 }
 
 impl<Message> canvas::Program<Message> for MemGraph {
     type State = ();
     fn draw(&self, _: &Self::State, renderer: &iced::Renderer, _: &iced::Theme, bounds: iced::Rectangle, _: iced::mouse::Cursor)
         -> Vec<canvas::Geometry> {
-        let palette = self.theme.palette();
+        let palette = self.palette;
         let mut frame = Frame::new(renderer, bounds.size());
         let w = bounds.width;
         let h = bounds.height;
@@ -201,19 +525,40 @@ impl Application for SystemMonitor {
     type Executor = executor::Default;
     type Message = Message;
     type Theme = iced::Theme;
-    type Flags = ();
+    type Flags = StartupFlags;
 
-    fn new(_flags: Self::Flags) -> (Self, Command<Self::Message>) {
+    fn new(flags: Self::Flags) -> (Self, Command<Self::Message>) {
         let mut system = System::new_all();
         system.refresh_all();
         (Self {
             system,
             cpu_usage: 0.0,
             memory_usage_mb: 0.0,
-            current_theme: Theme::Dark,
-            screen: Screen::Main,
+            current_theme: flags.theme,
+            screen: flags.default_screen,
             cpu_history: vec![0.0; 100],
             memory_history: vec![0.0; 100],
+            rx_rate: 0.0,
+            tx_rate: 0.0,
+            total_rx: 0,
+            total_tx: 0,
+            rx_history: vec![0.0; 100],
+            tx_history: vec![0.0; 100],
+            prev_net_totals: None,
+            disks: Vec::new(),
+            prev_disk_io: HashMap::new(),
+            sensors: Vec::new(),
+            temperature_unit: TemperatureUnit::Celsius,
+            cpu_per_core_history: Vec::new(),
+            show_per_core_cpu: false,
+            active_palette: flags.palette,
+            palette_overrides: flags.palette_overrides,
+            update_rate_ms: flags.update_rate_ms,
+            process_sorting: ProcessSorting::Cpu,
+            sort_reverse: false,
+            pending_kill: None,
+            kill_error: None,
+            is_frozen: false,
         }, Command::none())
     }
 
@@ -222,20 +567,132 @@ impl Application for SystemMonitor {
     fn update(&mut self, msg: Self::Message) -> Command<Self::Message> {
         match msg {
             Message::Tick => {
+                if self.is_frozen {
+                    return Command::none();
+                }
                 self.system.refresh_all();
                 self.cpu_usage = self.system.global_cpu_info().cpu_usage();
                 self.memory_usage_mb = self.system.used_memory() as f64 / 1000000.0;
                 
                 self.cpu_history.push(self.cpu_usage);
                 if self.cpu_history.len() > 100 { self.cpu_history.remove(0); }
-                
+
+                let per_core_usage: Vec<f32> = self.system.cpus().iter().map(|cpu| cpu.cpu_usage()).collect();
+                if self.cpu_per_core_history.len() != per_core_usage.len() {
+                    self.cpu_per_core_history = vec![vec![0.0; 100]; per_core_usage.len()];
+                }
+                for (history, usage) in self.cpu_per_core_history.iter_mut().zip(per_core_usage.iter()) {
+                    history.push(*usage);
+                    if history.len() > 100 { history.remove(0); }
+                }
+
                 self.memory_history.push(self.memory_usage_mb as f32);
                 if self.memory_history.len() > 100 { self.memory_history.remove(0); }
+
+                let seconds_per_tick = self.update_rate_ms as f64 / 1000.0;
+
+                self.system.refresh_networks_list();
+                self.system.refresh_networks();
+                let (rx_total, tx_total) = self.system.networks().iter().fold(
+                    (0u64, 0u64),
+                    |(rx, tx), (_, data)| (rx + data.total_received(), tx + data.total_transmitted()),
+                );
+                let (rx_delta, tx_delta) = match self.prev_net_totals {
+                    Some((prev_rx, prev_tx)) => (
+                        rx_total.saturating_sub(prev_rx),
+                        tx_total.saturating_sub(prev_tx),
+                    ),
+                    None => (0, 0),
+                };
+                self.prev_net_totals = Some((rx_total, tx_total));
+                let rx_rate = rx_delta as f64 / seconds_per_tick;
+                let tx_rate = tx_delta as f64 / seconds_per_tick;
+                self.rx_rate = rx_rate;
+                self.tx_rate = tx_rate;
+                self.total_rx += rx_delta;
+                self.total_tx += tx_delta;
+
+                self.rx_history.push(rx_rate as f32);
+                if self.rx_history.len() > 100 { self.rx_history.remove(0); }
+
+                self.tx_history.push(tx_rate as f32);
+                if self.tx_history.len() > 100 { self.tx_history.remove(0); }
+
+                self.system.refresh_disks_list();
+                self.system.refresh_disks();
+                let mut disks = Vec::new();
+                for disk in self.system.disks() {
+                    let name = disk.name().to_string_lossy().to_string();
+                    let total_bytes = disk.total_space();
+                    let free_bytes = disk.available_space();
+                    let used_bytes = total_bytes.saturating_sub(free_bytes);
+                    let read_bytes = disk.read_bytes();
+                    let written_bytes = disk.written_bytes();
+
+                    let (read_rate, write_rate) = match self.prev_disk_io.get(&name) {
+                        Some((prev_read, prev_written)) => (
+                            read_bytes.saturating_sub(*prev_read) as f64 / seconds_per_tick,
+                            written_bytes.saturating_sub(*prev_written) as f64 / seconds_per_tick,
+                        ),
+                        None => (0.0, 0.0),
+                    };
+                    self.prev_disk_io.insert(name.clone(), (read_bytes, written_bytes));
+
+                    disks.push(DiskInfo {
+                        name,
+                        mount_point: disk.mount_point().display().to_string(),
+                        used_bytes,
+                        free_bytes,
+                        total_bytes,
+                        read_rate,
+                        write_rate,
+                    });
+                }
+                self.disks = disks;
+
+                self.system.refresh_components_list();
+                self.system.refresh_components();
+                self.sensors = self.system.components()
+                    .iter()
+                    .map(|component| (component.label().to_string(), component.temperature()))
+                    .collect();
             }
             Message::GoToGraph => self.screen = Screen::Graph,
+            Message::GoToDisks => self.screen = Screen::Disks,
             Message::BackToMain => self.screen = Screen::Main,
-            Message::ThemeChanged(theme) => self.current_theme = theme,
-            Message::EndTask(pid) => { if let Some(p) = self.system.process(pid) { p.kill(); } }
+            Message::ThemeChanged(theme) => {
+                self.current_theme = theme;
+                let mut palette = theme.palette();
+                self.palette_overrides.apply(&mut palette);
+                self.active_palette = palette;
+            }
+            Message::UnitChanged(unit) => self.temperature_unit = unit,
+            Message::TogglePerCoreCpu => self.show_per_core_cpu = !self.show_per_core_cpu,
+            Message::SortBy(sorting) => {
+                if self.process_sorting == sorting {
+                    self.sort_reverse = !self.sort_reverse;
+                } else {
+                    self.process_sorting = sorting;
+                    self.sort_reverse = false;
+                }
+            }
+            Message::RequestKill(pid, name) => {
+                self.kill_error = None;
+                self.pending_kill = Some((pid, name));
+            }
+            Message::ConfirmKill => {
+                if let Some((pid, _)) = self.pending_kill.take() {
+                    match self.system.process(pid) {
+                        Some(p) => {
+                            p.kill();
+                            self.kill_error = None;
+                        }
+                        None => self.kill_error = Some(format!("Process {} no longer exists", pid)),
+                    }
+                }
+            }
+            Message::CancelKill => self.pending_kill = None,
+            Message::ToggleFreeze => self.is_frozen = !self.is_frozen,
             Message::ExportCSV => {
                 let mut processes: Vec<_> = self.system.processes().values().collect();
                 processes.sort_by(|a, b| b.cpu_usage().partial_cmp(&a.cpu_usage()).unwrap());
@@ -269,17 +726,22 @@ impl Application for SystemMonitor {
         match self.screen {
             Screen::Main => self.main_view(),
             Screen::Graph => self.graph_view(),
+            Screen::Disks => self.disks_view(),
         }
     }
 
     fn subscription(&self) -> Subscription<Self::Message> {
-        iced::time::every(Duration::from_secs(1)).map(|_| Message::Tick)
+        iced::time::every(Duration::from_millis(self.update_rate_ms)).map(|_| Message::Tick)
     }
 }
 
 impl SystemMonitor {
+    fn palette(&self) -> ThemePalette {
+        self.active_palette
+    }
+
     fn main_view(&self) -> Element<Message> {
-        let palette = self.current_theme.palette();
+        let palette = self.palette();
         let cpu_count = self.system.cpus().len() as f32;
 
         let theme_selection = Row::new()
@@ -288,30 +750,100 @@ impl SystemMonitor {
             .push(radio::Radio::new("Dark", Theme::Dark, Some(self.current_theme), Message::ThemeChanged))
             .spacing(10);
 
+        let unit_selection = Row::new()
+            .push(Text::new("Temp Unit:").style(palette.foreground))
+            .push(radio::Radio::new("Celsius", TemperatureUnit::Celsius, Some(self.temperature_unit), Message::UnitChanged))
+            .push(radio::Radio::new("Fahrenheit", TemperatureUnit::Fahrenheit, Some(self.temperature_unit), Message::UnitChanged))
+            .push(radio::Radio::new("Kelvin", TemperatureUnit::Kelvin, Some(self.temperature_unit), Message::UnitChanged))
+            .spacing(10);
+
+        let frozen_indicator = if self.is_frozen { " | FROZEN" } else { "" };
         let header_info = Text::new(
-            format!("CPU Usage: {:.2}% | Memory: {:.2} MB", self.cpu_usage, self.memory_usage_mb)
+            format!(
+                "CPU Usage: {:.2}% | Memory: {:.2} MB | Total RX: {} | Total TX: {}{}",
+                self.cpu_usage,
+                self.memory_usage_mb,
+                format_bytes_total(self.total_rx),
+                format_bytes_total(self.total_tx),
+                frozen_indicator,
+            )
         ).style(palette.accent);
 
         let graph_button = Button::new(Text::new("View CPU & Memory Graphs")).on_press(Message::GoToGraph);
+        let disks_button = Button::new(Text::new("View Disks")).on_press(Message::GoToDisks);
         let export_button = Button::new(Text::new("Export Data (CSV)")).on_press(Message::ExportCSV);
+        let freeze_button = Button::new(Text::new(if self.is_frozen { "Unfreeze" } else { "Freeze" }))
+            .on_press(Message::ToggleFreeze);
 
         let controls = Row::new()
             .push(graph_button)
+            .push(disks_button)
             .push(export_button)
+            .push(freeze_button)
             .spacing(20)
             .align_items(Alignment::Center);
 
         let mut processes: Vec<_> = self.system.processes().values().collect();
-        processes.sort_by(|a, b| b.cpu_usage().partial_cmp(&a.cpu_usage()).unwrap());
+        match self.process_sorting {
+            ProcessSorting::Cpu => processes.sort_by(|a, b| b.cpu_usage().partial_cmp(&a.cpu_usage()).unwrap()),
+            ProcessSorting::Mem => processes.sort_by(|a, b| b.memory().cmp(&a.memory())),
+            ProcessSorting::Pid => processes.sort_by(|a, b| b.pid().cmp(&a.pid())),
+            ProcessSorting::Name => processes.sort_by(|a, b| b.name().cmp(a.name())),
+        }
+        if self.sort_reverse {
+            processes.reverse();
+        }
 
-        let mut rows = Column::new().spacing(0);
         let palette_header = palette.header_bg;
-        
+
+        let mut sensor_rows = Column::new().spacing(0);
+        let sensor_header_row = Row::new()
+            .push(Text::new("Sensor").width(Length::FillPortion(3)))
+            .push(Text::new(format!("Temp ({})", self.temperature_unit.suffix())).width(Length::FillPortion(1)))
+            .spacing(10)
+            .align_items(Alignment::Center);
+        sensor_rows = sensor_rows.push(
+            Container::new(sensor_header_row)
+                .style(iced::theme::Container::Custom(Box::new(RowContainerStyle(palette_header))))
+                .padding(5)
+        );
+        for (i, (label, celsius)) in self.sensors.iter().enumerate() {
+            let row = Row::new()
+                .push(Text::new(label.clone()).width(Length::FillPortion(3)))
+                .push(Text::new(format!("{:.1}", self.temperature_unit.convert(*celsius))).width(Length::FillPortion(1)))
+                .spacing(10)
+                .align_items(Alignment::Center);
+
+            let bg = if i % 2 == 0 {
+                let [r, g, b, _] = palette_header.into_rgba8();
+                Color::from_rgba(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 0.1)
+            } else { Color::TRANSPARENT };
+
+            sensor_rows = sensor_rows.push(
+                Container::new(row)
+                    .style(iced::theme::Container::Custom(Box::new(RowContainerStyle(bg))))
+                    .padding(5)
+            );
+        }
+
+        let mut rows = Column::new().spacing(0);
+
+        let sort_header = |sorting: ProcessSorting, width: Length| -> Element<Message> {
+            let arrow = if self.process_sorting == sorting {
+                if self.sort_reverse { " ^" } else { " v" }
+            } else { "" };
+            Button::new(Text::new(format!("{}{}", sorting.label(), arrow)))
+                .on_press(Message::SortBy(sorting))
+                .width(width)
+                .into()
+        };
+
         let header_row = Row::new()
             .push(Text::new("Action").width(Length::Shrink))
-            .push(Text::new("Process Name").width(Length::FillPortion(3)))
-            .push(Text::new("CPU (%)").width(Length::FillPortion(1)))
-            .push(Text::new("Memory (MB)").width(Length::FillPortion(2)))
+            .push(sort_header(ProcessSorting::Name, Length::FillPortion(3)))
+            .push(sort_header(ProcessSorting::Pid, Length::FillPortion(1)))
+            .push(sort_header(ProcessSorting::Cpu, Length::FillPortion(1)))
+            .push(sort_header(ProcessSorting::Mem, Length::FillPortion(2)))
             .spacing(10)
             .align_items(Alignment::Center);
         rows = rows.push(
@@ -324,8 +856,9 @@ impl SystemMonitor {
             let normalized_cpu_usage = process.cpu_usage() / cpu_count;
 
             let row = Row::new()
-                .push(Button::new(Text::new("End")).on_press(Message::EndTask(process.pid())).width(Length::Shrink))
+                .push(Button::new(Text::new("End")).on_press(Message::RequestKill(process.pid(), process.name().to_string())).width(Length::Shrink))
                 .push(Text::new(process.name()).width(Length::FillPortion(3)))
+                .push(Text::new(process.pid().to_string()).width(Length::FillPortion(1)))
                 .push(Text::new(format!("{:.2}", normalized_cpu_usage)).width(Length::FillPortion(1)))
                 .push(Text::new(format!("{:.2}", process.memory() as f64 / 1000000.0)).width(Length::FillPortion(2)))
                 .spacing(10)
@@ -343,51 +876,158 @@ impl SystemMonitor {
             );
         }
 
-        Container::new(
-            Column::new()
-                .push(theme_selection)
-                .push(header_info)
-                .push(controls)
-                .push(Scrollable::new(rows).height(Length::Fill))
+        let mut content = Column::new()
+            .push(theme_selection)
+            .push(unit_selection)
+            .push(header_info)
+            .push(controls)
+            .spacing(10);
+
+        if let Some((pid, name)) = &self.pending_kill {
+            let confirm_row = Row::new()
+                .push(Button::new(Text::new("Yes")).on_press(Message::ConfirmKill))
+                .push(Button::new(Text::new("No")).on_press(Message::CancelKill))
                 .spacing(10)
-        )
+                .align_items(Alignment::Center);
+
+            let dialog = Container::new(
+                Column::new()
+                    .push(Text::new(format!("Kill {} (PID {})?", name, pid)).style(palette.accent))
+                    .push(confirm_row)
+                    .spacing(10)
+            )
+            .style(iced::theme::Container::Custom(Box::new(RowContainerStyle(palette.header_bg))))
+            .padding(15);
+
+            content = content.push(dialog);
+        } else if let Some(error) = &self.kill_error {
+            content = content.push(Text::new(error.clone()).style(Color::from_rgb(0.9, 0.3, 0.3)));
+        }
+
+        content = content.push(sensor_rows).push(Scrollable::new(rows).height(Length::Fill));
+
+        Container::new(content)
         .width(Length::Fill)
         .height(Length::Fill)
         .padding(20)
-        .style(iced::theme::Container::Custom(Box::new(CustomContainerStyle(self.current_theme))))
+        .style(iced::theme::Container::Custom(Box::new(CustomContainerStyle(self.palette()))))
         .into()
     }
 
     fn graph_view(&self) -> Element<Message> {
-        let palette = self.current_theme.palette();
+        let palette = self.palette();
         let cpu_graph = Canvas::new(CpuGraph {
             history: self.cpu_history.clone(),
             current: self.cpu_usage,
-            theme: self.current_theme,
+            palette,
+            per_core_history: if self.show_per_core_cpu { Some(self.cpu_per_core_history.clone()) } else { None },
         }).width(Length::Fill).height(Length::Fixed(220.0));
 
+        let per_core_toggle = Button::new(Text::new(
+            if self.show_per_core_cpu { "Show Aggregate CPU" } else { "Show Per-Core CPU" }
+        )).on_press(Message::TogglePerCoreCpu);
+
         let mem_graph = Canvas::new(MemGraph {
             history: self.memory_history.clone(),
             current: self.memory_usage_mb as f32,
-            theme: self.current_theme,
+            palette,
+        }).width(Length::Fill).height(Length::Fixed(220.0));
+
+        let net_graph = Canvas::new(NetGraph {
+            rx_history: self.rx_history.clone(),
+            tx_history: self.tx_history.clone(),
+            rx_rate: self.rx_rate,
+            tx_rate: self.tx_rate,
+            palette,
         }).width(Length::Fill).height(Length::Fixed(220.0));
 
         let back_button = Button::new(Text::new("Back")).on_press(Message::BackToMain);
-        
+
         let back_and_export = Row::new()
             .spacing(20)
             .push(back_button);
 
+        Container::new(
+            Scrollable::new(
+                Column::new()
+                    .spacing(20)
+                    .align_items(Alignment::Center)
+                    .push(Text::new("CPU Usage Graph").size(20).style(palette.accent))
+                    .push(per_core_toggle)
+                    .push(cpu_graph)
+                    .push(Text::new("Memory Usage Graph").size(20).style(palette.accent))
+                    .push(mem_graph)
+                    .push(Text::new("Network Throughput").size(20).style(palette.accent))
+                    .push(net_graph)
+                    .push(
+                        Container::new(back_and_export)
+                            .width(Length::Shrink)
+                            .padding(10)
+                    )
+            )
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .padding(20)
+        .style(iced::theme::Container::Custom(Box::new(CustomContainerStyle(self.palette()))))
+        .into()
+    }
+
+    fn disks_view(&self) -> Element<Message> {
+        let palette = self.palette();
+        let palette_header = palette.header_bg;
+
+        let back_button = Button::new(Text::new("Back")).on_press(Message::BackToMain);
+
+        let mut rows = Column::new().spacing(0);
+
+        let header_row = Row::new()
+            .push(Text::new("Disk").width(Length::FillPortion(2)))
+            .push(Text::new("Mount").width(Length::FillPortion(2)))
+            .push(Text::new("Used").width(Length::FillPortion(1)))
+            .push(Text::new("Free").width(Length::FillPortion(1)))
+            .push(Text::new("Total").width(Length::FillPortion(1)))
+            .push(Text::new("R/s").width(Length::FillPortion(1)))
+            .push(Text::new("W/s").width(Length::FillPortion(1)))
+            .spacing(10)
+            .align_items(Alignment::Center);
+        rows = rows.push(
+            Container::new(header_row)
+                .style(iced::theme::Container::Custom(Box::new(RowContainerStyle(palette_header))))
+                .padding(5)
+        );
+
+        for (i, disk) in self.disks.iter().enumerate() {
+            let row = Row::new()
+                .push(Text::new(disk.name.clone()).width(Length::FillPortion(2)))
+                .push(Text::new(disk.mount_point.clone()).width(Length::FillPortion(2)))
+                .push(Text::new(format_bytes_total(disk.used_bytes)).width(Length::FillPortion(1)))
+                .push(Text::new(format_bytes_total(disk.free_bytes)).width(Length::FillPortion(1)))
+                .push(Text::new(format_bytes_total(disk.total_bytes)).width(Length::FillPortion(1)))
+                .push(Text::new(format_bytes_per_sec(disk.read_rate)).width(Length::FillPortion(1)))
+                .push(Text::new(format_bytes_per_sec(disk.write_rate)).width(Length::FillPortion(1)))
+                .spacing(10)
+                .align_items(Alignment::Center);
+
+            let bg = if i % 2 == 0 {
+                let [r, g, b, _] = palette_header.into_rgba8();
+                Color::from_rgba(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, 0.1)
+            } else { Color::TRANSPARENT };
+
+            rows = rows.push(
+                Container::new(row)
+                    .style(iced::theme::Container::Custom(Box::new(RowContainerStyle(bg))))
+                    .padding(5)
+            );
+        }
+
         Container::new(
             Column::new()
-                .spacing(20) 
-                .align_items(Alignment::Center) 
-                .push(Text::new("CPU Usage Graph").size(20).style(palette.accent))
-                .push(cpu_graph)
-                .push(Text::new("Memory Usage Graph").size(20).style(palette.accent))
-                .push(mem_graph)
+                .spacing(10)
+                .push(Text::new("Disks").size(20).style(palette.accent))
+                .push(Scrollable::new(rows).height(Length::Fill))
                 .push(
-                    Container::new(back_and_export)
+                    Container::new(Row::new().spacing(20).push(back_button))
                         .width(Length::Shrink)
                         .padding(10)
                 )
@@ -395,7 +1035,7 @@ impl SystemMonitor {
         .width(Length::Fill)
         .height(Length::Fill)
         .padding(20)
-        .style(iced::theme::Container::Custom(Box::new(CustomContainerStyle(self.current_theme))))
+        .style(iced::theme::Container::Custom(Box::new(CustomContainerStyle(self.palette()))))
         .into()
     }
 }